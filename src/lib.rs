@@ -50,6 +50,11 @@
 use std::fmt;
 use std::collections::VecDeque;
 
+mod model;
+pub use model::NgramModel;
+
+pub mod similarity;
+
 const WORD_SEP: &'static str = "\u{2060}";
 
 /// Iterator adaptor, allows you to call the method `.ngrams(n)` on your iterator, as long as the
@@ -70,12 +75,59 @@ const WORD_SEP: &'static str = "\u{2060}";
 pub trait Ngram<'a, T: 'a + Pad + fmt::Debug + Clone>: Iterator<Item=T>  where Self: Sized {
     #[allow(missing_docs)]
     fn ngrams(self, usize) -> Ngrams<'a, T>;
+
+    /// Produce k-skip-n-grams: n-gram-like windows that allow up to `k` tokens to be skipped
+    /// between the chosen members. `k = 0` is equivalent to `ngrams(n)`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ngrams::Ngram;
+    /// let s: Vec<_> = "the cat sat down".split(' ').skipgrams(2, 1).collect();
+    /// assert_eq!(s, vec![
+    ///     vec!["the", "cat"],
+    ///     vec!["the", "sat"],
+    ///     vec!["cat", "sat"],
+    ///     vec!["cat", "down"],
+    ///     vec!["sat", "down"],
+    /// ]);
+    /// ```
+    #[allow(missing_docs)]
+    fn skipgrams(self, usize, usize) -> Skipgrams<'a, T>;
+
+    /// Produce every contiguous sub-sequence whose length falls in `min_n..=max_n`, at every
+    /// position, in a single pass. This is the usual way to build mixed-order feature sets
+    /// (e.g. unigrams + bigrams + trigrams together) without walking the input once per `n`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ngrams::Ngram;
+    /// let s: Vec<_> = "one two three".split(' ').everygrams(1, 2).collect();
+    /// assert_eq!(s, vec![
+    ///     vec!["one"],
+    ///     vec!["one", "two"],
+    ///     vec!["two"],
+    ///     vec!["two", "three"],
+    ///     vec!["three"],
+    /// ]);
+    /// ```
+    #[allow(missing_docs)]
+    fn everygrams(self, usize, usize) -> Everygrams<'a, T>;
 }
 
 impl<'a, T: 'a + Pad + fmt::Debug + Clone, U: 'a + Iterator<Item=T>> Ngram<'a, T> for U {
     fn ngrams(self, n: usize) -> Ngrams<'a, T> {
         Ngrams::new(self, n)
     }
+
+    fn skipgrams(self, n: usize, k: usize) -> Skipgrams<'a, T> {
+        Skipgrams::new(self, n, k)
+    }
+
+    fn everygrams(self, min_n: usize, max_n: usize) -> Everygrams<'a, T> {
+        Everygrams::new(self, min_n, max_n)
+    }
 }
 
 /// Main data type, implements the logic on splitting and grouping n-grams
@@ -110,9 +162,45 @@ impl<'a, T: 'a + Pad + fmt::Debug + Clone + Sized> Ngrams<'a, T> {
     /// Include padding at the beginning and end of the input. By default, this crate includes
     /// implementations for some common data structures, that prepends and appends the "WORD_SEP"
     /// unicode character onto the input.
-    pub fn pad(mut self) -> Self {
+    pub fn pad(self) -> Self {
+        let symbol = T::symbol();
+        let len = T::len(self.num);
+        self.pad_config(len, len, symbol)
+    }
+
+    /// Pad with a custom symbol instead of the `Pad` implementation's default, e.g. a
+    /// conventional sentence boundary marker. Pads both ends, `T::len(n)` symbols each.
+    pub fn pad_with(self, symbol: T) -> Self {
+        let len = T::len(self.num);
+        self.pad_config(len, len, symbol)
+    }
+
+    /// Pad only the left side, with `count` copies of the default symbol. Useful for
+    /// left-to-right prediction, where trailing padding is meaningless.
+    pub fn pad_left(self, count: usize) -> Self {
+        let symbol = T::symbol();
+        self.pad_config(count, 0, symbol)
+    }
+
+    /// Pad only the right side, with `count` copies of the default symbol.
+    pub fn pad_right(self, count: usize) -> Self {
+        let symbol = T::symbol();
+        self.pad_config(0, count, symbol)
+    }
+
+    /// Pad only the left side, with `count` copies of a custom symbol.
+    pub fn pad_left_with(self, count: usize, symbol: T) -> Self {
+        self.pad_config(count, 0, symbol)
+    }
+
+    /// Pad only the right side, with `count` copies of a custom symbol.
+    pub fn pad_right_with(self, count: usize, symbol: T) -> Self {
+        self.pad_config(0, count, symbol)
+    }
+
+    fn pad_config(mut self, left: usize, right: usize, symbol: T) -> Self {
         self.pad = true;
-        self.source = Box::new(Padded::new(self.source, self.num));
+        self.source = Box::new(Padded::new_with(self.source, left, right, symbol));
         self
     }
 
@@ -149,32 +237,366 @@ impl<'a, T: 'a + Pad + fmt::Debug + Clone> Iterator for Ngrams<'a, T> {
     }
 }
 
-/*
-impl<'a, T: 'a + Pad + fmt::Debug + Clone> Iterator for &'a Ngrams<'a, T> {
-    type Item = Vec<&'a T>;
+/// Implements the logic on splitting and grouping k-skip-n-grams. A k-skip-n-gram selects `n`
+/// tokens in order from a window of `n + k` consecutive tokens, allowing up to `k` tokens in
+/// between the chosen ones to be skipped.
+pub struct Skipgrams<'a, T: 'a + Pad + fmt::Debug + Clone> {
+    source: Box<Iterator<Item = T> + 'a>,
+    n: usize,
+    memsize: usize,
+    memory: VecDeque<T>,
+    pending: VecDeque<Vec<T>>,
+    exhausted: bool,
+}
+
+impl<'a, T: 'a + Pad + fmt::Debug + Clone> fmt::Debug for Skipgrams<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Skipgrams(tokens, N, K)")
+    }
+}
+
+impl<'a, T: 'a + Pad + fmt::Debug + Clone + Sized> Skipgrams<'a, T> {
+    /// The source for `Skipgrams` is expected to be pre-tokenized, same as `Ngrams::new`.
+    pub fn new<V: 'a + Iterator<Item = T>>(source: V, n: usize, k: usize) -> Skipgrams<'a, T> {
+        let memsize = n + k - 1;
+        Skipgrams {
+            source: Box::new(source),
+            n: n,
+            memsize: memsize,
+            memory: VecDeque::with_capacity(memsize),
+            pending: VecDeque::new(),
+            exhausted: false,
+        }
+    }
+
+    /// Include padding at the beginning and end of the input, same as `Ngrams::pad`.
+    pub fn pad(mut self) -> Self {
+        self.source = Box::new(Padded::new(self.source, self.n));
+        self
+    }
+
+    fn fill_memory(&mut self) {
+        while self.memory.len() < self.memsize {
+            let a = self.source.next().unwrap();
+            self.memory.push_back(a);
+        }
+    }
+
+    /// Once the source is exhausted, `memory` still holds up to `memsize` trailing tokens
+    /// that never got to anchor a full `n + k` window. Shrink that window one position at a
+    /// time, emitting every remaining skip-gram anchored at its first token, down to the
+    /// point where fewer than `n` tokens are left to choose from.
+    fn drain_tail(&mut self) {
+        while self.memory.len() >= self.n {
+            let window: Vec<T> = self.memory.iter().cloned().collect();
+
+            for combo in combinations_including_first(window.len(), self.n) {
+                let gram = combo.iter().map(|&i| window[i].clone()).collect();
+                self.pending.push_back(gram);
+            }
+
+            let _ = self.memory.pop_front();
+        }
+    }
+}
+
+impl<'a, T: 'a + Pad + fmt::Debug + Clone> Iterator for Skipgrams<'a, T> {
+    type Item = Vec<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.fill_memory();
-        let next_item = self.source.next();
+        loop {
+            if let Some(gram) = self.pending.pop_front() {
+                return Some(gram);
+            }
+
+            if self.exhausted {
+                self.drain_tail();
+                return self.pending.pop_front();
+            }
+
+            self.fill_memory();
 
-        match next_item {
-            None => None,
-            Some(n) => {
-                let mut result = Vec::with_capacity(self.num);
+            match self.source.next() {
+                None => self.exhausted = true,
+                Some(tok) => {
+                    let mut window: Vec<T> = Vec::with_capacity(self.memsize + 1);
+                    window.extend(self.memory.iter().cloned());
+                    window.push(tok.clone());
 
-                for elem in &self.memory {
+                    for combo in combinations_including_first(window.len(), self.n) {
+                        let gram = combo.iter().map(|&i| window[i].clone()).collect();
+                        self.pending.push_back(gram);
+                    }
+
+                    let _ = self.memory.pop_front();
+                    self.memory.push_back(tok);
                 }
-                result.push(&n);
+            }
+        }
+    }
+}
+
+/// Implements the logic on splitting and grouping everygrams: at each position, every
+/// contiguous sub-sequence whose length falls in `min_n..=max_n`.
+pub struct Everygrams<'a, T: 'a + Pad + fmt::Debug + Clone> {
+    source: Box<Iterator<Item = T> + 'a>,
+    min_n: usize,
+    max_n: usize,
+    memory: VecDeque<T>,
+    pending: VecDeque<Vec<T>>,
+}
+
+impl<'a, T: 'a + Pad + fmt::Debug + Clone> fmt::Debug for Everygrams<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Everygrams(tokens, MIN_N, MAX_N)")
+    }
+}
 
-                let _ = self.memory.pop_front();
-                self.memory.push_back(n.clone());
+impl<'a, T: 'a + Pad + fmt::Debug + Clone + Sized> Everygrams<'a, T> {
+    /// The source for `Everygrams` is expected to be pre-tokenized, same as `Ngrams::new`.
+    pub fn new<V: 'a + Iterator<Item = T>>(source: V, min_n: usize, max_n: usize) -> Everygrams<'a, T> {
+        Everygrams {
+            source: Box::new(source),
+            min_n: min_n,
+            max_n: max_n,
+            memory: VecDeque::with_capacity(max_n),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Include padding at the beginning and end of the input, same as `Ngrams::pad`. Padding
+    /// length tracks `max_n - 1`, so the longest gram is always fully represented at the edges.
+    pub fn pad(mut self) -> Self {
+        self.source = Box::new(Padded::new(self.source, self.max_n));
+        self
+    }
+}
 
-                Some(result)
+impl<'a, T: 'a + Pad + fmt::Debug + Clone> Iterator for Everygrams<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(gram) = self.pending.pop_front() {
+                return Some(gram);
+            }
+
+            match self.source.next() {
+                None => return None,
+                Some(tok) => {
+                    self.memory.push_back(tok);
+                    if self.memory.len() > self.max_n {
+                        let _ = self.memory.pop_front();
+                    }
+
+                    let wlen = self.memory.len();
+                    let window: Vec<T> = self.memory.iter().cloned().collect();
+
+                    for len in (self.min_n..=self.max_n).rev() {
+                        if len <= wlen {
+                            let gram = window[(wlen - len)..].to_vec();
+                            self.pending.push_back(gram);
+                        }
+                    }
+                }
             }
         }
     }
 }
-*/
+
+/// All strictly-increasing combinations of `choose` indices drawn from `0..total`, that
+/// include index `0`. Used to pick the non-skipped positions out of a k-skip-n-gram window.
+fn combinations_including_first(total: usize, choose: usize) -> Vec<Vec<usize>> {
+    let rest: Vec<usize> = (1..total).collect();
+    combinations(&rest, choose - 1)
+        .into_iter()
+        .map(|mut tail| {
+            let mut combo = vec![0];
+            combo.append(&mut tail);
+            combo
+        })
+        .collect()
+}
+
+/// All strictly-increasing combinations of `k` elements drawn from `pool`, preserving order.
+fn combinations(pool: &[usize], k: usize) -> Vec<Vec<usize>> {
+    if k == 0 {
+        return vec![Vec::new()];
+    }
+
+    if pool.len() < k {
+        return Vec::new();
+    }
+
+    let mut result = Vec::new();
+
+    for i in 0..=(pool.len() - k) {
+        for mut tail in combinations(&pool[i + 1..], k - 1) {
+            let mut combo = vec![pool[i]];
+            combo.append(&mut tail);
+            result.push(combo);
+        }
+    }
+
+    result
+}
+
+/// Iterator adaptor, allows you to call `.ngrams_ref(n)` directly on a slice you already hold
+/// to get n-grams without cloning any elements. Unlike `Ngram::ngrams`, this does not require
+/// `T: Clone`, since it simply borrows sliding windows out of the slice you already own.
+///
+/// If your tokens come from a lazy `Iterator<Item = T>` instead of an already-collected slice
+/// (e.g. a large stream you don't want to buffer up front), see `NgramRefStream::ngrams_ref`.
+///
+/// Padding isn't supported here directly, since padding needs somewhere to own the extra
+/// symbols; use `padded_vec` to build a padded `Vec<T>` first and call `ngrams_ref` on that.
+///
+/// ## Example
+///
+/// ```rust
+/// use ngrams::NgramRef;
+/// let tokens = vec!["one", "two", "three", "four"];
+/// let grams: Vec<_> = tokens.ngrams_ref(2).collect();
+/// assert_eq!(grams, vec![
+///     &["one", "two"][..],
+///     &["two", "three"][..],
+///     &["three", "four"][..],
+/// ]);
+/// ```
+pub trait NgramRef<'a, T: 'a> {
+    #[allow(missing_docs)]
+    fn ngrams_ref(self, n: usize) -> RefNgrams<'a, T>;
+}
+
+impl<'a, T: 'a> NgramRef<'a, T> for &'a [T] {
+    fn ngrams_ref(self, n: usize) -> RefNgrams<'a, T> {
+        RefNgrams { windows: self.windows(n) }
+    }
+}
+
+/// Borrowing counterpart to `Ngrams`; yields `&[T]` windows over a slice without cloning.
+pub struct RefNgrams<'a, T: 'a> {
+    windows: ::std::slice::Windows<'a, T>,
+}
+
+impl<'a, T: 'a> fmt::Debug for RefNgrams<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RefNgrams(slice, N)")
+    }
+}
+
+impl<'a, T: 'a> Iterator for RefNgrams<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.windows.next()
+    }
+}
+
+/// Adaptor analogous to `Ngram::ngrams`, but for a lazy `Iterator<Item = T>` you don't want to
+/// fully buffer up front: `.ngrams_ref(n)` keeps the most recent tokens (at most `2 * n`) in a
+/// contiguous `Vec<T>` and hands back borrowed windows into it, so a large token stream can be
+/// n-grammed without allocating a fresh `Vec` or cloning `n` items per position. Unlike
+/// `Ngram::ngrams`, this does not require `T: Clone`.
+///
+/// The returned `NgramsRef` is not a `std::iter::Iterator`: each window borrows from it, so the
+/// borrow can't outlive the next call. Drive it with `next_ref` in a `while let` loop instead.
+///
+/// ## Example
+///
+/// ```rust
+/// use ngrams::NgramRefStream;
+/// let mut it = vec![1, 2, 3, 4].into_iter().ngrams_ref(2);
+/// let mut grams = Vec::new();
+/// while let Some(window) = it.next_ref() {
+///     grams.push(window.to_vec());
+/// }
+/// assert_eq!(grams, vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+/// ```
+pub trait NgramRefStream<'a, T: 'a>: Iterator<Item = T> where Self: Sized {
+    #[allow(missing_docs)]
+    fn ngrams_ref(self, n: usize) -> NgramsRef<'a, T>;
+}
+
+impl<'a, T: 'a, U: 'a + Iterator<Item = T>> NgramRefStream<'a, T> for U {
+    fn ngrams_ref(self, n: usize) -> NgramsRef<'a, T> {
+        NgramsRef::new(self, n)
+    }
+}
+
+/// Ring-buffer-backed n-gram extraction over a streaming `Iterator<Item = T>`; see
+/// `NgramRefStream::ngrams_ref`.
+pub struct NgramsRef<'a, T: 'a> {
+    source: Box<Iterator<Item = T> + 'a>,
+    buf: Vec<T>,
+    n: usize,
+}
+
+impl<'a, T: 'a> fmt::Debug for NgramsRef<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NgramsRef(tokens, N)")
+    }
+}
+
+impl<'a, T: 'a> NgramsRef<'a, T> {
+    /// The source for `NgramsRef` is expected to be pre-tokenized, same as `Ngrams::new`.
+    pub fn new<V: 'a + Iterator<Item = T>>(source: V, n: usize) -> NgramsRef<'a, T> {
+        NgramsRef {
+            source: Box::new(source),
+            buf: Vec::with_capacity(2 * n),
+            n: n,
+        }
+    }
+
+    /// Pulls the next token into the ring buffer and returns the resulting window, or `None`
+    /// once the source is exhausted. The returned slice borrows from `self`, so it must be
+    /// used (or copied out of) before the next call.
+    ///
+    /// `buf` is allowed to grow to `2 * n` before being compacted back down to the trailing
+    /// `n - 1` tokens, so the copy needed to keep the window contiguous is paid once every
+    /// `n + 1` tokens rather than on every single one.
+    pub fn next_ref(&mut self) -> Option<&[T]> {
+        loop {
+            match self.source.next() {
+                None => return None,
+                Some(tok) => {
+                    if self.buf.len() == 2 * self.n {
+                        let keep_from = self.buf.len() - (self.n - 1);
+                        self.buf.drain(..keep_from);
+                    }
+
+                    self.buf.push(tok);
+
+                    if self.buf.len() >= self.n {
+                        let end = self.buf.len();
+                        return Some(&self.buf[(end - self.n)..end]);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Builds a new, owned `Vec<T>` with `T::len(n)` padding symbols prepended and appended to
+/// `tokens`. Pair this with `NgramRef::ngrams_ref` to get zero-copy padded n-grams: the clones
+/// happen once, up front, rather than once per gram as `Ngrams::pad` does.
+pub fn padded_vec<T: Pad + Clone>(tokens: &[T], n: usize) -> Vec<T> {
+    let pad_len = T::len(n);
+    let symbol = T::symbol();
+    let mut result = Vec::with_capacity(tokens.len() + pad_len * 2);
+
+    for _ in 0..pad_len {
+        result.push(symbol.clone());
+    }
+
+    result.extend_from_slice(tokens);
+
+    for _ in 0..pad_len {
+        result.push(symbol.clone());
+    }
+
+    result
+}
 
 /// Implement this so `ngrams` knows how to pad the beginning and end of your input.
 ///
@@ -215,7 +637,7 @@ impl Pad for char {
 
 struct Padded<'a, T: 'a + Pad + fmt::Debug + Clone> {
     source: Box<Iterator<Item = T> + 'a>,
-    len: usize,
+    back_len: usize,
     symbol: T,
     remaining: usize,
     end: bool,
@@ -224,11 +646,19 @@ struct Padded<'a, T: 'a + Pad + fmt::Debug + Clone> {
 impl<'a, T: 'a + Pad + fmt::Debug + Clone> Padded<'a, T> {
     fn new<U: 'a + Iterator<Item = T> + Sized>(source: U, n: usize) -> Padded<'a, T> {
         let l = T::len(n);
+        Padded::new_with(source, l, l, T::symbol())
+    }
+
+    fn new_with<U: 'a + Iterator<Item = T> + Sized>(source: U,
+                                                     front_len: usize,
+                                                     back_len: usize,
+                                                     symbol: T)
+                                                     -> Padded<'a, T> {
         Padded {
             source: Box::new(source),
-            len: l,
-            symbol: T::symbol(),
-            remaining: l,
+            back_len: back_len,
+            symbol: symbol,
+            remaining: front_len,
             end: false,
         }
     }
@@ -251,7 +681,7 @@ impl<'a, T: 'a + Pad + fmt::Debug + Clone> Iterator for Padded<'a, T> {
                 // then this is the first time
                 // we have seen this return None.
                 self.end = true;
-                self.remaining = self.len;
+                self.remaining = self.back_len;
             }
 
             if self.remaining > 0 {
@@ -268,7 +698,7 @@ impl<'a, T: 'a + Pad + fmt::Debug + Clone> Iterator for Padded<'a, T> {
 #[cfg(test)]
 mod tests {
 
-    use super::{Ngram, Ngrams};
+    use super::{Ngram, Ngrams, NgramRef, NgramRefStream, Skipgrams, Everygrams, padded_vec};
     use std::string::ToString;
 
     #[test]
@@ -365,4 +795,216 @@ mod tests {
                 vec!["r", "i", "n", "g"],
             ]);
     }
+
+    #[test]
+    fn test_everygrams() {
+        let seq = "one two three".split(' ');
+        let result: Vec<_> = Everygrams::new(seq, 1, 2).collect();
+        assert_eq!(result,
+                   vec![
+                vec!["one"],
+                vec!["one", "two"],
+                vec!["two"],
+                vec!["two", "three"],
+                vec!["three"],
+            ]);
+    }
+
+    #[test]
+    fn test_everygrams_padded() {
+        let seq = "one two".split(' ');
+        let result: Vec<_> = Everygrams::new(seq, 1, 2).pad().collect();
+        assert_eq!(result,
+                   vec![
+                vec!["\u{2060}"],
+                vec!["\u{2060}", "one"],
+                vec!["one"],
+                vec!["one", "two"],
+                vec!["two"],
+                vec!["two", "\u{2060}"],
+                vec!["\u{2060}"],
+            ]);
+    }
+
+    #[test]
+    fn test_pad_with_custom_symbol() {
+        let seq = "one two".split(' ');
+        let result: Vec<_> = Ngrams::new(seq, 2).pad_with("<s>").collect();
+        assert_eq!(result,
+                   vec![
+                vec!["<s>", "one"],
+                vec!["one", "two"],
+                vec!["two", "<s>"],
+            ]);
+    }
+
+    #[test]
+    fn test_pad_left_only() {
+        let seq = "one two".split(' ');
+        let result: Vec<_> = Ngrams::new(seq, 2).pad_left(1).collect();
+        assert_eq!(result,
+                   vec![
+                vec!["\u{2060}", "one"],
+                vec!["one", "two"],
+            ]);
+    }
+
+    #[test]
+    fn test_pad_right_with_custom_count_and_symbol() {
+        let seq = "one two".split(' ');
+        let result: Vec<_> = Ngrams::new(seq, 2).pad_right_with(2, "</s>").collect();
+        assert_eq!(result,
+                   vec![
+                vec!["one", "two"],
+                vec!["two", "</s>"],
+                vec!["</s>", "</s>"],
+            ]);
+    }
+
+    #[test]
+    fn test_skipgrams() {
+        let seq = "the cat sat down".split(' ');
+        let result: Vec<_> = Skipgrams::new(seq, 2, 1).collect();
+        assert_eq!(result,
+                   vec![
+                vec!["the", "cat"],
+                vec!["the", "sat"],
+                vec!["cat", "sat"],
+                vec!["cat", "down"],
+                vec!["sat", "down"],
+            ]);
+    }
+
+    #[test]
+    fn test_skipgrams_includes_trailing_anchors() {
+        // Regression test: every position with at least `n` tokens left must anchor a
+        // skip-gram, including the `k` such positions past the last full `n + k`-token
+        // window, which never get to pull a full window before the source runs out.
+        let seq = "a b c d e".split(' ');
+        let result: Vec<_> = Skipgrams::new(seq, 2, 1).collect();
+        assert_eq!(result,
+                   vec![
+                vec!["a", "b"],
+                vec!["a", "c"],
+                vec!["b", "c"],
+                vec!["b", "d"],
+                vec!["c", "d"],
+                vec!["c", "e"],
+                vec!["d", "e"],
+            ]);
+    }
+
+    #[test]
+    fn test_skipgrams_includes_trailing_anchors_wider_skip() {
+        let seq = "a b c d e f".split(' ');
+        let result: Vec<_> = Skipgrams::new(seq, 3, 2).collect();
+        assert_eq!(result,
+                   vec![
+                vec!["a", "b", "c"],
+                vec!["a", "b", "d"],
+                vec!["a", "b", "e"],
+                vec!["a", "c", "d"],
+                vec!["a", "c", "e"],
+                vec!["a", "d", "e"],
+                vec!["b", "c", "d"],
+                vec!["b", "c", "e"],
+                vec!["b", "c", "f"],
+                vec!["b", "d", "e"],
+                vec!["b", "d", "f"],
+                vec!["b", "e", "f"],
+                vec!["c", "d", "e"],
+                vec!["c", "d", "f"],
+                vec!["c", "e", "f"],
+                vec!["d", "e", "f"],
+            ]);
+    }
+
+    #[test]
+    fn test_skipgrams_no_skip_matches_ngrams() {
+        let seq = "one two three four".split(' ');
+        let skip: Vec<_> = Skipgrams::new(seq.clone(), 2, 0).collect();
+        let plain: Vec<_> = Ngrams::new(seq, 2).collect();
+        assert_eq!(skip, plain);
+    }
+
+    #[test]
+    fn test_skipgrams_padded() {
+        let seq = "the cat".split(' ');
+        let result: Vec<_> = Skipgrams::new(seq, 2, 1).pad().collect();
+        assert_eq!(result,
+                   vec![
+                vec!["\u{2060}", "the"],
+                vec!["\u{2060}", "cat"],
+                vec!["the", "cat"],
+                vec!["the", "\u{2060}"],
+                vec!["cat", "\u{2060}"],
+            ]);
+    }
+
+    #[test]
+    fn test_ngrams_ref() {
+        let tokens = ["one", "two", "three", "four"];
+        let result: Vec<_> = tokens.ngrams_ref(2).collect();
+        assert_eq!(result,
+                   vec![
+                &["one", "two"][..],
+                &["two", "three"][..],
+                &["three", "four"][..],
+            ]);
+    }
+
+    #[test]
+    fn test_ngrams_ref_padded() {
+        let tokens = vec!["one", "two", "three"];
+        let padded = padded_vec(&tokens, 2);
+        let result: Vec<_> = padded.ngrams_ref(2).collect();
+        assert_eq!(result,
+                   vec![
+                &["\u{2060}", "one"][..],
+                &["one", "two"][..],
+                &["two", "three"][..],
+                &["three", "\u{2060}"][..],
+            ]);
+    }
+
+    #[test]
+    fn test_ngrams_ref_stream() {
+        let mut it = vec![1, 2, 3, 4].into_iter().ngrams_ref(2);
+        let mut grams = Vec::new();
+
+        while let Some(window) = it.next_ref() {
+            grams.push(window.to_vec());
+        }
+
+        assert_eq!(grams, vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+    }
+
+    #[test]
+    fn test_ngrams_ref_stream_past_buffer_compaction() {
+        // n = 2, so the internal buffer compacts once it holds 4 tokens (at the 5th token,
+        // then every 3rd); this drives it through a compaction to make sure the window
+        // keeps sliding correctly across it.
+        let mut it = (1..=7).ngrams_ref(2);
+        let mut grams = Vec::new();
+
+        while let Some(window) = it.next_ref() {
+            grams.push(window.to_vec());
+        }
+
+        assert_eq!(grams,
+                   vec![
+                vec![1, 2],
+                vec![2, 3],
+                vec![3, 4],
+                vec![4, 5],
+                vec![5, 6],
+                vec![6, 7],
+            ]);
+    }
+
+    #[test]
+    fn test_ngrams_ref_stream_shorter_than_n() {
+        let mut it = vec![1].into_iter().ngrams_ref(2);
+        assert_eq!(it.next_ref(), None);
+    }
 }
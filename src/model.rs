@@ -0,0 +1,186 @@
+//! A frequency-based n-gram language model: counts n-grams and their `(n-1)`-length contexts
+//! and exposes maximum-likelihood (optionally Laplace/Lidstone-smoothed) next-token
+//! probabilities, built from the `Vec<T>` grams that `Ngrams` produces.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::cmp::Ordering;
+
+/// Counts n-grams of a fixed order `n` and their `(n-1)`-length contexts, and answers
+/// probability queries over them.
+///
+/// ## Example
+///
+/// ```rust
+/// use ngrams::{Ngram, NgramModel};
+///
+/// let mut model = NgramModel::new(2);
+/// model.train("a b a b a c".split(' ').ngrams(2));
+/// assert_eq!(model.probability(&["a"], &"b"), 2.0 / 3.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NgramModel<T: Eq + Hash + Clone> {
+    n: usize,
+    ngram_counts: HashMap<Vec<T>, usize>,
+    context_counts: HashMap<Vec<T>, usize>,
+    vocab: HashSet<T>,
+}
+
+impl<T: Eq + Hash + Clone> NgramModel<T> {
+    /// Creates an empty model for n-grams of order `n`.
+    pub fn new(n: usize) -> NgramModel<T> {
+        NgramModel {
+            n: n,
+            ngram_counts: HashMap::new(),
+            context_counts: HashMap::new(),
+            vocab: HashSet::new(),
+        }
+    }
+
+    /// Feeds a sequence of already-extracted n-grams (each of length `n`, as produced by
+    /// `Ngrams`) into the model, updating its counts.
+    pub fn train<I: IntoIterator<Item = Vec<T>>>(&mut self, grams: I) {
+        for gram in grams {
+            assert_eq!(gram.len(), self.n, "NgramModel::train expects grams of length n");
+
+            for token in &gram {
+                self.vocab.insert(token.clone());
+            }
+
+            let context = gram[..self.n - 1].to_vec();
+            *self.context_counts.entry(context).or_insert(0) += 1;
+            *self.ngram_counts.entry(gram).or_insert(0) += 1;
+        }
+    }
+
+    /// The number of times the full n-gram `context + [token]` was seen during training.
+    pub fn count(&self, context: &[T], token: &T) -> usize {
+        let mut gram = context.to_vec();
+        gram.push(token.clone());
+        self.ngram_counts.get(&gram).cloned().unwrap_or(0)
+    }
+
+    /// The number of times `context` was seen as the leading `(n-1)` tokens of an n-gram.
+    pub fn context_count(&self, context: &[T]) -> usize {
+        self.context_counts.get(context).cloned().unwrap_or(0)
+    }
+
+    /// The maximum-likelihood estimate `count(context + token) / count(context)`.
+    ///
+    /// Returns `0.0` for a context that was never observed.
+    pub fn probability(&self, context: &[T], token: &T) -> f64 {
+        self.probability_smoothed(context, token, 0.0)
+    }
+
+    /// Additive (Laplace/Lidstone) smoothed probability:
+    /// `(count(context + token) + alpha) / (count(context) + alpha * V)`, where `V` is the
+    /// observed vocabulary size. `alpha = 0.0` is equivalent to `probability`.
+    pub fn probability_smoothed(&self, context: &[T], token: &T, alpha: f64) -> f64 {
+        let numerator = self.count(context, token) as f64 + alpha;
+        let denominator = self.context_count(context) as f64 + alpha * self.vocab.len() as f64;
+
+        if denominator == 0.0 {
+            0.0
+        } else {
+            numerator / denominator
+        }
+    }
+
+    /// The `k` most likely continuations of `context`, ranked by unsmoothed probability, with
+    /// ties broken by the token itself so the result is deterministic regardless of the
+    /// underlying `HashMap`'s iteration order.
+    pub fn top_k(&self, context: &[T], k: usize) -> Vec<(T, f64)>
+        where T: Ord
+    {
+        let mut scored: Vec<(T, f64)> = self.ngram_counts.keys()
+            .filter(|gram| gram.len() == self.n && &gram[..self.n - 1] == context)
+            .map(|gram| {
+                let token = gram[self.n - 1].clone();
+                let p = self.probability(context, &token);
+                (token, p)
+            })
+            .collect();
+
+        scored.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal).then_with(|| a.0.cmp(&b.0))
+        });
+        scored.truncate(k);
+        scored
+    }
+
+    /// The perplexity of a sequence of n-grams under this model: `exp(-1/N * sum(ln p_i))`.
+    /// An unseen n-gram makes the result infinite, as is standard for unsmoothed perplexity.
+    pub fn perplexity(&self, sequence: &[Vec<T>]) -> f64 {
+        let n = sequence.len() as f64;
+        let log_sum: f64 = sequence.iter()
+            .map(|gram| {
+                let context = &gram[..self.n - 1];
+                let token = &gram[self.n - 1];
+                self.probability(context, token).ln()
+            })
+            .sum();
+
+        (-log_sum / n).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::NgramModel;
+    use super::super::Ngram;
+
+    #[test]
+    fn test_probability() {
+        let mut model = NgramModel::new(2);
+        model.train("a b a b a c".split(' ').ngrams(2));
+
+        assert_eq!(model.probability(&["a"], &"b"), 2.0 / 3.0);
+        assert_eq!(model.probability(&["a"], &"c"), 1.0 / 3.0);
+        assert_eq!(model.probability(&["x"], &"y"), 0.0);
+    }
+
+    #[test]
+    fn test_probability_smoothed() {
+        let mut model = NgramModel::new(2);
+        model.train("a b a b".split(' ').ngrams(2));
+
+        // vocab = {a, b}, context "a" seen twice, "a b" seen twice
+        let p = model.probability_smoothed(&["a"], &"b", 1.0);
+        assert_eq!(p, (2.0 + 1.0) / (2.0 + 1.0 * 2.0));
+    }
+
+    #[test]
+    fn test_top_k() {
+        let mut model = NgramModel::new(2);
+        model.train("a b a b a c".split(' ').ngrams(2));
+
+        let top = model.top_k(&["a"], 1);
+        assert_eq!(top, vec![("b", 2.0 / 3.0)]);
+    }
+
+    #[test]
+    fn test_top_k_ties_broken_deterministically() {
+        let mut model = NgramModel::new(2);
+        model.train("x b x c x a".split(' ').ngrams(2));
+
+        // "b", "c" and "a" are all equally likely continuations of "x"; the tie should
+        // always resolve alphabetically, not by HashMap iteration order.
+        let top = model.top_k(&["x"], 3);
+        assert_eq!(top,
+                   vec![
+                ("a", 1.0 / 3.0),
+                ("b", 1.0 / 3.0),
+                ("c", 1.0 / 3.0),
+            ]);
+    }
+
+    #[test]
+    fn test_perplexity_of_training_data_is_finite() {
+        let mut model = NgramModel::new(2);
+        let grams: Vec<_> = "a b a b a c".split(' ').ngrams(2).collect();
+        model.train(grams.clone());
+
+        assert!(model.perplexity(&grams).is_finite());
+    }
+}
@@ -0,0 +1,188 @@
+//! N-gram-based fuzzy string similarity and a searchable inverted index, built on the same
+//! character n-gram extraction as the rest of this crate.
+
+use std::collections::{HashMap, HashSet};
+use std::cmp::Ordering;
+use Ngrams;
+
+fn char_ngrams(s: &str, n: usize, pad: bool) -> HashSet<Vec<char>> {
+    if pad {
+        Ngrams::new(s.chars(), n).pad().collect()
+    } else {
+        // Without padding, `Ngrams` needs at least `n` tokens to ever fill its internal
+        // memory buffer; fewer than that yields no n-grams, not a panic.
+        let tokens: Vec<char> = s.chars().collect();
+        if tokens.len() < n {
+            HashSet::new()
+        } else {
+            Ngrams::new(tokens.into_iter(), n).collect()
+        }
+    }
+}
+
+/// A set-based similarity coefficient over n-grams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Coefficient {
+    /// `2 * |A ∩ B| / (|A| + |B|)`
+    Dice,
+    /// `|A ∩ B| / |A ∪ B|`
+    Jaccard,
+}
+
+fn score(a: &HashSet<Vec<char>>, b: &HashSet<Vec<char>>, coefficient: Coefficient) -> f64 {
+    let intersection = a.intersection(b).count() as f64;
+
+    match coefficient {
+        Coefficient::Dice => {
+            let denom = a.len() + b.len();
+            if denom == 0 { 0.0 } else { 2.0 * intersection / denom as f64 }
+        }
+        Coefficient::Jaccard => {
+            let union = a.union(b).count();
+            if union == 0 { 0.0 } else { intersection / union as f64 }
+        }
+    }
+}
+
+/// The Dice coefficient between the `n`-character-gram sets of `a` and `b`. Pass `pad = true`
+/// so word boundaries contribute, matching `Ngrams::pad`.
+///
+/// ## Example
+///
+/// ```rust
+/// use ngrams::similarity::dice;
+/// assert_eq!(dice("night", "nacht", 2, false), 2.0 * 1.0 / (4.0 + 4.0));
+/// ```
+pub fn dice(a: &str, b: &str, n: usize, pad: bool) -> f64 {
+    score(&char_ngrams(a, n, pad), &char_ngrams(b, n, pad), Coefficient::Dice)
+}
+
+/// The Jaccard coefficient between the `n`-character-gram sets of `a` and `b`.
+pub fn jaccard(a: &str, b: &str, n: usize, pad: bool) -> f64 {
+    score(&char_ngrams(a, n, pad), &char_ngrams(b, n, pad), Coefficient::Jaccard)
+}
+
+/// An inverted index over the character n-grams of many strings, for fuzzy, typo-tolerant
+/// lookup. `search` gathers candidates by unioning the posting lists of the query's n-grams,
+/// then scores and ranks only those candidates.
+#[derive(Debug, Clone)]
+pub struct NgramIndex {
+    n: usize,
+    pad: bool,
+    coefficient: Coefficient,
+    items: Vec<String>,
+    grams: Vec<HashSet<Vec<char>>>,
+    postings: HashMap<Vec<char>, HashSet<usize>>,
+}
+
+impl NgramIndex {
+    /// Creates an empty index using `n`-character grams, padded, scored with the Dice
+    /// coefficient.
+    pub fn new(n: usize) -> NgramIndex {
+        NgramIndex {
+            n: n,
+            pad: true,
+            coefficient: Coefficient::Dice,
+            items: Vec::new(),
+            grams: Vec::new(),
+            postings: HashMap::new(),
+        }
+    }
+
+    /// Scores candidates with the Jaccard coefficient instead of the default Dice coefficient.
+    pub fn with_jaccard(mut self) -> Self {
+        self.coefficient = Coefficient::Jaccard;
+        self
+    }
+
+    /// Disables the padding that `new` enables by default.
+    pub fn without_padding(mut self) -> Self {
+        self.pad = false;
+        self
+    }
+
+    /// Inserts `s` into the index, returning the id it was assigned.
+    pub fn insert(&mut self, s: &str) -> usize {
+        let id = self.items.len();
+        let grams = char_ngrams(s, self.n, self.pad);
+
+        for gram in &grams {
+            self.postings.entry(gram.clone()).or_default().insert(id);
+        }
+
+        self.items.push(s.to_owned());
+        self.grams.push(grams);
+        id
+    }
+
+    /// The string stored at `id`.
+    pub fn get(&self, id: usize) -> &str {
+        &self.items[id]
+    }
+
+    /// Items whose n-gram similarity to `query` is at least `threshold`, ranked by score
+    /// (highest first).
+    pub fn search(&self, query: &str, threshold: f64) -> Vec<(usize, f64)> {
+        let query_grams = char_ngrams(query, self.n, self.pad);
+
+        let mut candidates = HashSet::new();
+        for gram in &query_grams {
+            if let Some(ids) = self.postings.get(gram) {
+                candidates.extend(ids.iter().cloned());
+            }
+        }
+
+        let mut scored: Vec<(usize, f64)> = candidates.into_iter()
+            .map(|id| (id, score(&query_grams, &self.grams[id], self.coefficient)))
+            .filter(|&(_, s)| s >= threshold)
+            .collect();
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+        scored
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{dice, jaccard, NgramIndex};
+
+    #[test]
+    fn test_dice_identical() {
+        assert_eq!(dice("hello", "hello", 2, false), 1.0);
+    }
+
+    #[test]
+    fn test_dice_disjoint() {
+        assert_eq!(dice("ab", "xy", 2, false), 0.0);
+    }
+
+    #[test]
+    fn test_jaccard_identical() {
+        assert_eq!(jaccard("hello", "hello", 2, false), 1.0);
+    }
+
+    #[test]
+    fn test_dice_shorter_than_n_unpadded_does_not_panic() {
+        assert_eq!(dice("a", "bb", 3, false), 0.0);
+        assert_eq!(jaccard("a", "bb", 3, false), 0.0);
+    }
+
+    #[test]
+    fn test_ngram_index_without_padding_short_string_does_not_panic() {
+        let mut index = NgramIndex::new(3).without_padding();
+        index.insert("a");
+
+        assert_eq!(index.search("a", 0.0), Vec::new());
+    }
+
+    #[test]
+    fn test_ngram_index_search() {
+        let mut index = NgramIndex::new(2);
+        let night = index.insert("night");
+        index.insert("banana");
+
+        let results = index.search("nacht", 0.1);
+        assert_eq!(results[0].0, night);
+    }
+}